@@ -0,0 +1,10 @@
+/// How a `Grid` treats coordinates past its edges when counting neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BoundaryMode {
+    /// Cells past the edge are treated as permanently dead (the default).
+    #[default]
+    Dead,
+    /// The grid wraps around: the left edge connects to the right, and the
+    /// top edge connects to the bottom.
+    Toroidal,
+}
@@ -0,0 +1,141 @@
+use crate::BoundaryMode;
+
+const NEIGHBOUR_OFFSETS: [(i32, i32); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Two preallocated flat `Vec<bool>` buffers (indexed `y * width + x`) that
+/// a generation step reads from (`front`) and writes into (`back`), flipping
+/// which is which instead of cloning the whole generation every tick.
+#[derive(Debug, Clone)]
+pub struct DoubleBuffer {
+    width: i32,
+    height: i32,
+    buffers: [Vec<bool>; 2],
+    switch: bool,
+}
+
+impl DoubleBuffer {
+    pub fn new(width: i32, height: i32) -> DoubleBuffer {
+        let size = (width.max(0) * height.max(0)) as usize;
+
+        DoubleBuffer {
+            width,
+            height,
+            buffers: [vec![false; size], vec![false; size]],
+            switch: false,
+        }
+    }
+
+    fn index(&self, x: i32, y: i32) -> usize {
+        (y * self.width + x) as usize
+    }
+
+    /// The buffer holding the current generation.
+    pub fn front(&self) -> &[bool] {
+        &self.buffers[self.switch as usize]
+    }
+
+    /// The buffer that the next generation is written into.
+    pub fn back(&self) -> &[bool] {
+        &self.buffers[!self.switch as usize]
+    }
+
+    pub fn get(&self, x: i32, y: i32) -> bool {
+        self.front()[self.index(x, y)]
+    }
+
+    pub fn set_front(&mut self, x: i32, y: i32, is_alive: bool) {
+        let index = self.index(x, y);
+        self.buffers[self.switch as usize][index] = is_alive;
+    }
+
+    pub fn set_back(&mut self, x: i32, y: i32, is_alive: bool) {
+        let index = self.index(x, y);
+        self.buffers[!self.switch as usize][index] = is_alive;
+    }
+
+    /// Flips which buffer is the front, making the generation just written
+    /// into `back` the current one.
+    pub fn flip(&mut self) {
+        self.switch = !self.switch;
+    }
+
+    /// Counts live neighbours of `(x, y)` in the front buffer. Under
+    /// `BoundaryMode::Dead`, coordinates outside the grid are treated as
+    /// dead; under `BoundaryMode::Toroidal`, they wrap around to the
+    /// opposite edge.
+    pub fn count_live_neighbours(&self, x: i32, y: i32, boundary: BoundaryMode) -> usize {
+        NEIGHBOUR_OFFSETS
+            .iter()
+            .filter(|(dx, dy)| match boundary {
+                BoundaryMode::Dead => {
+                    let (nx, ny) = (x + dx, y + dy);
+                    nx >= 0 && nx < self.width && ny >= 0 && ny < self.height && self.get(nx, ny)
+                }
+                BoundaryMode::Toroidal => {
+                    let nx = (x + dx + self.width) % self.width;
+                    let ny = (y + dy + self.height) % self.height;
+                    self.get(nx, ny)
+                }
+            })
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_start_all_dead() {
+        let buffer = DoubleBuffer::new(3, 3);
+
+        assert!(buffer.front().iter().all(|alive| !alive));
+    }
+
+    #[test]
+    fn it_should_flip_front_and_back() {
+        let mut buffer = DoubleBuffer::new(2, 2);
+
+        buffer.set_back(0, 0, true);
+        buffer.flip();
+
+        assert!(buffer.get(0, 0));
+    }
+
+    #[test]
+    fn it_should_expose_back_as_the_buffer_not_yet_flipped_into() {
+        let mut buffer = DoubleBuffer::new(2, 2);
+
+        buffer.set_back(0, 0, true);
+
+        assert!(buffer.back()[0]);
+        assert!(!buffer.front()[0]);
+    }
+
+    #[test]
+    fn it_should_count_live_neighbours_ignoring_out_of_bounds() {
+        let mut buffer = DoubleBuffer::new(3, 3);
+        buffer.set_front(0, 0, true);
+        buffer.set_front(1, 0, true);
+
+        assert_eq!(buffer.count_live_neighbours(0, 0, BoundaryMode::Dead), 1);
+    }
+
+    #[test]
+    fn it_should_wrap_neighbours_in_toroidal_mode() {
+        let mut buffer = DoubleBuffer::new(3, 3);
+        buffer.set_front(0, 0, true);
+
+        assert_eq!(buffer.count_live_neighbours(2, 2, BoundaryMode::Dead), 0);
+        assert_eq!(buffer.count_live_neighbours(2, 2, BoundaryMode::Toroidal), 1);
+    }
+}
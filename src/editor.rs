@@ -0,0 +1,55 @@
+use std::io::{stdout, Write};
+use std::time::Duration;
+
+use termion::event::Key;
+use termion::input::TermRead;
+use termion::raw::IntoRawMode;
+
+use crate::Game;
+
+/// Drives the interactive editor loop for `game`. See `Game::run_editor`
+/// for the key bindings.
+pub fn run(game: &mut Game) {
+    let mut stdout = stdout().into_raw_mode().expect("failed to enter raw mode");
+    let mut keys = termion::async_stdin().keys();
+
+    let mut cursor = (0, 0);
+    let mut paused = true;
+
+    loop {
+        if let Some(Ok(key)) = keys.next() {
+            match key {
+                Key::Char('q') => break,
+                Key::Up | Key::Char('k') => cursor.1 = (cursor.1 - 1).max(0),
+                Key::Down | Key::Char('j') => {
+                    cursor.1 = (cursor.1 + 1).min(game.grid.height() - 1)
+                }
+                Key::Left | Key::Char('h') => cursor.0 = (cursor.0 - 1).max(0),
+                Key::Right | Key::Char('l') => {
+                    cursor.0 = (cursor.0 + 1).min(game.grid.width() - 1)
+                }
+                Key::Char(' ') => {
+                    game.grid.toggle_cell(cursor.0, cursor.1);
+                }
+                Key::Char('p') => paused = !paused,
+                Key::Char('n') => {
+                    game.grid.next_state();
+                }
+                Key::Char('c') => game.grid.clear(),
+                Key::Char('r') => game.grid.randomize(),
+                _ => {}
+            }
+        }
+
+        if !paused {
+            game.grid.next_state();
+        }
+
+        game.grid.render_with_cursor(cursor);
+        stdout.flush().expect("failed to flush stdout");
+
+        std::thread::sleep(Duration::from_millis(game.frame_delay));
+    }
+
+    write!(stdout, "{}", termion::cursor::Show).expect("failed to restore cursor");
+}
@@ -1,4 +1,17 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+use rand::seq::SliceRandom;
+
+mod boundary;
+mod double_buffer;
+mod editor;
+mod pattern;
+mod rule;
+mod sparse_grid;
+pub use boundary::BoundaryMode;
+use double_buffer::DoubleBuffer;
+pub use rule::Rule;
+pub use sparse_grid::SparseGrid;
 
 const DEAD_CELL: char = '⬛';
 const LIVE_CELL: char = '⬜';
@@ -14,10 +27,21 @@ pub struct Grid {
     width: i32,
     height: i32,
     pub cells: HashMap<(i32, i32), Cell>,
+    rule: Rule,
+    buffer: DoubleBuffer,
+    boundary: BoundaryMode,
 }
 
 impl Grid {
     pub fn new(width: i32, height: i32) -> Grid {
+        Grid::with_rule(width, height, Rule::default())
+    }
+
+    pub fn with_rule(width: i32, height: i32, rule: Rule) -> Grid {
+        Grid::with_options(width, height, rule, BoundaryMode::default())
+    }
+
+    pub fn with_options(width: i32, height: i32, rule: Rule, boundary: BoundaryMode) -> Grid {
         let mut cells = HashMap::new();
         for y in 0..height {
             for x in 0..width {
@@ -28,28 +52,50 @@ impl Grid {
             width,
             height,
             cells,
+            rule,
+            buffer: DoubleBuffer::new(width, height),
+            boundary,
         }
     }
 
     pub fn from_seed(width: i32, height: i32, live_cells: &[(i32, i32)]) -> Grid {
         let mut grid = Grid::new(width, height);
         for (x, y) in live_cells {
-            grid.cells.get_mut(&(*x, *y)).unwrap().toggle();
+            grid.toggle_cell(*x, *y);
         }
         grid
     }
 
     pub fn random(width: i32, height: i32) -> Grid {
+        Grid::random_with_rule(width, height, Rule::default())
+    }
+
+    pub fn random_with_rule(width: i32, height: i32, rule: Rule) -> Grid {
+        Grid::random_with_options(width, height, rule, BoundaryMode::default())
+    }
+
+    pub fn random_with_options(
+        width: i32,
+        height: i32,
+        rule: Rule,
+        boundary: BoundaryMode,
+    ) -> Grid {
         let mut cells = HashMap::new();
+        let mut buffer = DoubleBuffer::new(width, height);
         for y in 0..height {
             for x in 0..width {
-                cells.insert((x, y), Cell::new(x, y, rand::random()));
+                let is_alive = rand::random();
+                cells.insert((x, y), Cell::new(x, y, is_alive));
+                buffer.set_front(x, y, is_alive);
             }
         }
         Grid {
             width,
             height,
             cells,
+            rule,
+            buffer,
+            boundary,
         }
     }
 
@@ -77,22 +123,43 @@ impl Grid {
         }
 
         let mut new_cell = opt_cell.unwrap().clone();
+        new_cell.toggle();
 
-        self.cells.insert((x, y), new_cell.toggle());
+        self.buffer.set_front(x, y, new_cell.is_alive());
+        self.cells.insert((x, y), new_cell);
 
         self
     }
 
     pub fn next_state(&mut self) -> &Grid {
-        let mut this = self.clone();
+        let rule = self.rule;
 
-        self.cells.iter().for_each(|(key, cell)| {
-            let live_neighbors = cell.live_neighbours_count(&this);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let live_neighbours = self.buffer.count_live_neighbours(x, y, self.boundary);
+                let is_alive = self.buffer.get(x, y);
+                let count = live_neighbours.min(8);
+
+                let next_alive = if is_alive {
+                    rule.survival[count]
+                } else {
+                    rule.birth[count]
+                };
+
+                self.buffer.set_back(x, y, next_alive);
+            }
+        }
 
-            this.cells.insert(*key, cell.next_state(live_neighbors));
-        });
+        self.buffer.flip();
 
-        self.cells = this.cells;
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let next_alive = self.buffer.get(x, y);
+                if let Some(cell) = self.cells.get_mut(&(x, y)) {
+                    cell.advance(next_alive);
+                }
+            }
+        }
 
         self
     }
@@ -103,7 +170,7 @@ impl Grid {
         for y in 0..self.height {
             for x in 0..self.width {
                 let cell = self.cell(x, y).unwrap();
-                output.push(if cell.is_alive { LIVE_CELL } else { DEAD_CELL });
+                output.push_str(&glyph_for_age(cell.age()));
             }
             output.push('\n');
         }
@@ -111,28 +178,174 @@ impl Grid {
         print!("{}", termion::cursor::Goto(1, 1));
         print!("{}{}", termion::clear::All, output);
     }
+
+    /// Renders the grid like `render`, but highlights the cell at `cursor`
+    /// with a background color, for the interactive editor.
+    pub fn render_with_cursor(&self, cursor: (i32, i32)) {
+        let mut output = String::new();
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let cell = self.cell(x, y).unwrap();
+                let glyph = glyph_for_age(cell.age());
+
+                if (x, y) == cursor {
+                    output.push_str(&format!(
+                        "{}{}{}",
+                        termion::color::Bg(termion::color::Rgb(70, 70, 200)),
+                        glyph,
+                        termion::color::Bg(termion::color::Reset)
+                    ));
+                } else {
+                    output.push_str(&glyph);
+                }
+            }
+            output.push('\n');
+        }
+
+        print!("{}", termion::cursor::Goto(1, 1));
+        print!("{}{}", termion::clear::All, output);
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    /// Kills every cell, resetting the grid to empty.
+    pub fn clear(&mut self) {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                self.cells.insert((x, y), Cell::new(x, y, false));
+                self.buffer.set_front(x, y, false);
+            }
+        }
+    }
+
+    /// Replaces every cell with a new random state.
+    pub fn randomize(&mut self) {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let is_alive = rand::random();
+                self.cells.insert((x, y), Cell::new(x, y, is_alive));
+                self.buffer.set_front(x, y, is_alive);
+            }
+        }
+    }
+
+    /// The coordinates of all currently live cells.
+    pub fn live_cells(&self) -> HashSet<(i32, i32)> {
+        self.cells
+            .iter()
+            .filter(|(_, cell)| cell.is_alive())
+            .map(|(coordinate, _)| *coordinate)
+            .collect()
+    }
+
+    /// Randomly flips up to `population` dead cells to alive, injecting
+    /// fresh activity into a grid that has settled down.
+    pub fn reseed(&mut self, population: usize) {
+        let mut dead_coordinates: Vec<(i32, i32)> = self
+            .cells
+            .iter()
+            .filter(|(_, cell)| !cell.is_alive())
+            .map(|(coordinate, _)| *coordinate)
+            .collect();
+
+        dead_coordinates.shuffle(&mut rand::thread_rng());
+
+        for (x, y) in dead_coordinates.into_iter().take(population) {
+            self.toggle_cell(x, y);
+        }
+    }
+}
+
+/// Number of generations a recently-dead cell keeps a fading trail before
+/// rendering fully dark.
+const FADE_GENERATIONS: u32 = 4;
+
+/// Maps a cell's age to a colored glyph: newly-born cells render bright,
+/// long-lived cells settle into a steadier color, and recently-dead cells
+/// fade out over `FADE_GENERATIONS` ticks before going fully dark.
+fn glyph_for_age(age: Age) -> String {
+    match age {
+        Age::Alive(0) => colored_cell(LIVE_CELL, 255, 255, 255),
+        Age::Alive(n) => {
+            let green = 80u8.saturating_add((n.min(6) as u8) * 25);
+            colored_cell(LIVE_CELL, 0, green, 0)
+        }
+        Age::Dead(n) if n < FADE_GENERATIONS => {
+            let shade = 160u8.saturating_sub((n as u8) * (160 / FADE_GENERATIONS as u8));
+            colored_cell(LIVE_CELL, shade, shade, shade)
+        }
+        Age::Dead(_) => colored_cell(DEAD_CELL, 0, 0, 0),
+    }
+}
+
+fn colored_cell(glyph: char, r: u8, g: u8, b: u8) -> String {
+    format!(
+        "{}{}{}",
+        termion::color::Fg(termion::color::Rgb(r, g, b)),
+        glyph,
+        termion::color::Fg(termion::color::Reset)
+    )
 }
 
 pub struct Game {
     pub grid: Grid,
     pub max_generations: usize,
     pub frame_delay: u64,
+    /// Every `seed_interval` generations, `seed_population` dead cells are
+    /// randomly brought to life. `0` disables reseeding.
+    pub seed_interval: usize,
+    pub seed_population: usize,
+    /// Stop and hold the final frame once the board reaches a stable or
+    /// empty state, instead of looping until `max_generations`.
+    pub quiet: bool,
 }
 
 impl Game {
+    /// Wraps an already-configured `grid` (rule and boundary mode are set
+    /// via `Grid::with_options`/`Grid::random_with_options`, not here).
     pub fn new(grid: Grid, max_generations: usize, frame_delay: u64) -> Game {
         Game {
             grid,
             max_generations,
             frame_delay,
+            seed_interval: 0,
+            seed_population: 0,
+            quiet: false,
         }
     }
 
     pub fn run(&mut self) {
         let mut current_generation = 0;
+        let mut previous_live_cells = self.grid.live_cells();
 
         'game_loop: loop {
-            self.grid.next_state().render();
+            self.grid.next_state();
+            let live_cells = self.grid.live_cells();
+
+            if self.quiet && live_cells == previous_live_cells {
+                self.grid.render();
+                println!(
+                    "Generation: {}/{} (stable, holding final frame)",
+                    current_generation + 1,
+                    self.max_generations
+                );
+                break 'game_loop;
+            }
+
+            previous_live_cells = live_cells;
+
+            if self.seed_interval > 0 && current_generation % self.seed_interval == 0 {
+                self.grid.reseed(self.seed_population);
+            }
+
+            self.grid.render();
 
             println!(
                 "Generation: {}/{}",
@@ -148,39 +361,65 @@ impl Game {
             std::thread::sleep(std::time::Duration::from_millis(self.frame_delay));
         }
     }
+
+    /// Runs an interactive terminal editor over this game's grid: move the
+    /// cursor with the arrow keys or hjkl, `space` toggles the cell under
+    /// it, `p` pauses/resumes stepping, `n` single-steps while paused, `c`
+    /// clears the grid, `r` randomizes it, and `q` quits. Starts paused so
+    /// a pattern can be placed before running.
+    pub fn run_editor(&mut self) {
+        editor::run(self)
+    }
+}
+
+/// How long a cell has held its current state: the number of generations
+/// it has been alive, or the number of generations since it died.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Age {
+    Alive(u32),
+    Dead(u32),
 }
 
 #[derive(Debug, Clone, Copy)]
 pub struct Cell {
     x: i32,
     y: i32,
-    is_alive: bool,
+    age: Age,
 }
 
 impl Cell {
     fn new(x: i32, y: i32, is_alive: bool) -> Cell {
         Cell {
-            x: x,
-            y: y,
-            is_alive,
+            x,
+            y,
+            age: if is_alive { Age::Alive(0) } else { Age::Dead(0) },
         }
     }
 
     fn neighbours(&self, grid: &Grid) -> Vec<Cell> {
-        let coordinates = [
-            (self.x - 1, self.y - 1),
-            (self.x, self.y - 1),
-            (self.x + 1, self.y - 1),
-            (self.x - 1, self.y),
-            (self.x + 1, self.y),
-            (self.x - 1, self.y + 1),
-            (self.x, self.y + 1),
-            (self.x + 1, self.y + 1),
+        let offsets = [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (-1, 0),
+            (1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
         ];
 
-        coordinates
+        offsets
             .iter()
-            .filter_map(|(x, y)| grid.cell(*x, *y))
+            .filter_map(|(dx, dy)| {
+                let (x, y) = match grid.boundary {
+                    BoundaryMode::Dead => (self.x + dx, self.y + dy),
+                    BoundaryMode::Toroidal => (
+                        (self.x + dx + grid.width) % grid.width,
+                        (self.y + dy + grid.height) % grid.height,
+                    ),
+                };
+                grid.cell(x, y)
+            })
             .map(|cell| *cell)
             .collect::<Vec<Cell>>()
     }
@@ -188,37 +427,162 @@ impl Cell {
     pub fn live_neighbours_count(&self, grid: &Grid) -> usize {
         self.neighbours(grid)
             .iter()
-            .filter(|cell| cell.is_alive)
+            .filter(|cell| cell.is_alive())
             .collect::<Vec<&Cell>>()
             .len()
     }
 
+    pub fn is_alive(&self) -> bool {
+        matches!(self.age, Age::Alive(_))
+    }
+
+    pub fn age(&self) -> Age {
+        self.age
+    }
+
     pub fn toggle(&mut self) -> Cell {
-        self.is_alive = !self.is_alive;
+        self.age = if self.is_alive() {
+            Age::Dead(0)
+        } else {
+            Age::Alive(0)
+        };
         *self
     }
 
-    /// # next_state
-    /// Returns the next state of the cell given the number of live neighbours.
-    ///
-    /// ## Rules
-    /// 1. Any live cell with fewer than two live neighbours dies, as if caused by underpopulation.
-    /// 2. Any live cell with two or three live neighbours lives on to the next generation.
-    /// 3. Any live cell with more than three live neighbours dies, as if by overpopulation.
-    /// 4. Any dead cell with exactly three live neighbours becomes a live cell, as if by reproduction.
-    ///
-    /// ```
-    pub fn next_state(&self, live_neighbour_count: usize) -> Cell {
-        let mut cell = self.clone();
-
-        match (self.is_alive, live_neighbour_count) {
-            (true, 0 | 1) => cell.is_alive = false,
-            (true, 2 | 3) => cell.is_alive = true,
-            (true, _) => cell.is_alive = false,
-            (false, 3) => cell.is_alive = true,
-            (false, _) => cell.is_alive = false,
-        }
-
-        cell
+    /// Advances the cell's age given whether it is alive in the next
+    /// generation: age increments on survival, resets to `Age::Alive(0)` on
+    /// birth, and starts the since-death counter at `Age::Dead(0)` on death.
+    fn advance(&mut self, next_alive: bool) {
+        self.age = match (self.age, next_alive) {
+            (Age::Alive(n), true) => Age::Alive(n + 1),
+            (Age::Alive(_), false) => Age::Dead(0),
+            (Age::Dead(_), true) => Age::Alive(0),
+            (Age::Dead(n), false) => Age::Dead(n + 1),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cell_neighbors_agrees_with_next_state_under_dead_boundary() {
+        let grid = Grid::from_seed(3, 3, &[(0, 0)]);
+
+        // top-left corner only has 3 in-bounds neighbours off a Dead-bounded grid
+        assert_eq!(grid.cell_neighbors(0, 0).unwrap().len(), 3);
+        assert_eq!(
+            grid.cell_neighbors(0, 0)
+                .unwrap()
+                .iter()
+                .filter(|cell| cell.is_alive())
+                .count(),
+            0
+        );
+        // the neighbour count next_state reads from the buffer matches
+        assert_eq!(
+            grid.cell(1, 0).unwrap().live_neighbours_count(&grid),
+            grid.buffer.count_live_neighbours(1, 0, grid.boundary)
+        );
+    }
+
+    #[test]
+    fn cell_neighbors_wraps_under_toroidal_boundary() {
+        let mut grid = Grid::with_options(3, 3, Rule::default(), BoundaryMode::Toroidal);
+        grid.toggle_cell(2, 2);
+
+        let live_neighbour_count = grid
+            .cell_neighbors(0, 0)
+            .unwrap()
+            .iter()
+            .filter(|cell| cell.is_alive())
+            .count();
+
+        assert_eq!(live_neighbour_count, 1);
+    }
+
+    #[test]
+    fn advance_increments_age_on_survival() {
+        let mut cell = Cell::new(0, 0, true);
+
+        cell.advance(true);
+        assert_eq!(cell.age(), Age::Alive(1));
+
+        cell.advance(true);
+        assert_eq!(cell.age(), Age::Alive(2));
+    }
+
+    #[test]
+    fn advance_starts_since_death_counter_on_death() {
+        let mut cell = Cell::new(0, 0, true);
+
+        cell.advance(false);
+
+        assert_eq!(cell.age(), Age::Dead(0));
+    }
+
+    #[test]
+    fn advance_resets_age_on_birth() {
+        let mut cell = Cell::new(0, 0, false);
+
+        cell.advance(true);
+
+        assert_eq!(cell.age(), Age::Alive(0));
+    }
+
+    #[test]
+    fn advance_increments_since_death_counter_while_staying_dead() {
+        let mut cell = Cell::new(0, 0, false);
+
+        cell.advance(false);
+        assert_eq!(cell.age(), Age::Dead(1));
+
+        cell.advance(false);
+        assert_eq!(cell.age(), Age::Dead(2));
+    }
+
+    #[test]
+    fn glyph_for_age_fades_then_goes_fully_dark_at_the_boundary() {
+        let fading = glyph_for_age(Age::Dead(FADE_GENERATIONS - 1));
+        let dark = glyph_for_age(Age::Dead(FADE_GENERATIONS));
+
+        assert!(fading.contains(LIVE_CELL));
+        assert!(dark.contains(DEAD_CELL));
+        assert!(!dark.contains(LIVE_CELL));
+    }
+
+    #[test]
+    fn reseed_brings_the_requested_number_of_dead_cells_to_life() {
+        let mut grid = Grid::new(5, 5);
+
+        grid.reseed(5);
+
+        assert_eq!(grid.live_cells().len(), 5);
+    }
+
+    #[test]
+    fn quiet_mode_stops_once_the_board_is_stable() {
+        let grid = Grid::new(2, 2);
+        let mut game = Game::new(grid, 1000, 0);
+        game.quiet = true;
+
+        game.run();
+
+        // an empty board stays empty, so quiet mode should stop right away
+        // instead of looping to max_generations
+        assert!(game.grid.live_cells().is_empty());
+    }
+
+    #[test]
+    fn seed_interval_injects_population_into_a_dead_board() {
+        let grid = Grid::new(5, 5);
+        let mut game = Game::new(grid, 0, 0);
+        game.seed_interval = 1;
+        game.seed_population = 3;
+
+        game.run();
+
+        assert_eq!(game.grid.live_cells().len(), 3);
     }
 }
@@ -1,24 +1,39 @@
-use game_of_life::Grid;
+use std::env;
+use std::fs;
+
+use game_of_life::{Game, Grid, Rule};
 
 fn main() {
     const MAX_GENERATIONS: usize = 100;
     const FRAME_DELAY: u64 = (1000 / 60) * 8;
 
-    let mut current_generation = 0;
-
-    let mut new_grid = Grid::random(24, 24);
+    let args: Vec<String> = env::args().collect();
 
-    'game_loop: loop {
-        new_grid.next_state().render();
+    let edit_mode = args.iter().any(|arg| arg == "--edit" || arg == "-e");
+    let pattern_path = args
+        .iter()
+        .position(|arg| arg == "--pattern" || arg == "-p")
+        .and_then(|index| args.get(index + 1));
 
-        println!("Generation: {}", current_generation);
-        println!("\nhit ctrl-c to exit\n");
+    let grid = match pattern_path {
+        Some(path) => {
+            let text = fs::read_to_string(path)
+                .unwrap_or_else(|err| panic!("failed to read pattern file {}: {}", path, err));
 
-        if current_generation == MAX_GENERATIONS {
-            break 'game_loop;
+            if path.ends_with(".rle") {
+                Grid::from_rle(&text)
+            } else {
+                Grid::from_plaintext(&text)
+            }
         }
-        current_generation += 1;
-        // delay printing to console
-        std::thread::sleep(std::time::Duration::from_millis(FRAME_DELAY));
+        None => Grid::random_with_rule(24, 24, Rule::default()),
+    };
+
+    let mut game = Game::new(grid, MAX_GENERATIONS, FRAME_DELAY);
+
+    if edit_mode {
+        game.run_editor();
+    } else {
+        game.run();
     }
 }
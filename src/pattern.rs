@@ -0,0 +1,193 @@
+use crate::{Grid, Rule};
+
+impl Grid {
+    /// Parses a plaintext pattern where `.`/`0`/space is dead and any other
+    /// character is live, one row per line. The grid is sized to the
+    /// largest line length and the number of lines.
+    pub fn from_plaintext(text: &str) -> Grid {
+        let lines: Vec<&str> = text.lines().collect();
+        let height = lines.len() as i32;
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0) as i32;
+
+        let mut grid = Grid::new(width.max(1), height.max(1));
+
+        for (y, line) in lines.iter().enumerate() {
+            for (x, ch) in line.chars().enumerate() {
+                if !matches!(ch, '.' | '0' | ' ') {
+                    grid.toggle_cell(x as i32, y as i32);
+                }
+            }
+        }
+
+        grid
+    }
+
+    /// Parses Golly's run-length-encoded (RLE) format: `#`-prefixed comment
+    /// lines are skipped, the `x = m, y = n[, rule = ...]` header sizes the
+    /// grid, and the body is decoded where a leading integer is a run count
+    /// applied to the following tag (`b` dead, `o` live, `$` end of row, `!`
+    /// end of pattern).
+    pub fn from_rle(text: &str) -> Grid {
+        let mut width = 0i32;
+        let mut height = 0i32;
+        let mut rule = Rule::default();
+        let mut body = String::new();
+
+        for line in text.lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if line.starts_with('x') {
+                for field in line.split(',') {
+                    let mut sides = field.splitn(2, '=');
+                    let key = sides.next().unwrap_or("").trim();
+                    let value = sides.next().unwrap_or("").trim();
+
+                    match key {
+                        "x" => width = value.parse().unwrap_or(0),
+                        "y" => height = value.parse().unwrap_or(0),
+                        "rule" => rule = Rule::parse(value),
+                        _ => {}
+                    }
+                }
+                continue;
+            }
+
+            body.push_str(line);
+        }
+
+        let mut grid = Grid::with_rule(width.max(1), height.max(1), rule);
+
+        let mut x = 0i32;
+        let mut y = 0i32;
+        let mut run_count = String::new();
+
+        for ch in body.chars() {
+            if ch.is_ascii_digit() {
+                run_count.push(ch);
+                continue;
+            }
+
+            let count: i32 = run_count.drain(..).as_str().parse().unwrap_or(1);
+
+            match ch {
+                'b' => x += count,
+                'o' => {
+                    for _ in 0..count {
+                        if grid.cell(x, y).is_some() {
+                            grid.toggle_cell(x, y);
+                        }
+                        x += 1;
+                    }
+                }
+                '$' => {
+                    y += count;
+                    x = 0;
+                }
+                '!' => break,
+                _ => {}
+            }
+        }
+
+        grid
+    }
+
+    /// Serializes the grid to Golly's run-length-encoded (RLE) format, so
+    /// patterns produced here round-trip through `from_rle`.
+    pub fn to_rle(&self) -> String {
+        let mut output = format!("x = {}, y = {}, rule = {}\n", self.width, self.height, self.rule);
+        let mut row_runs = Vec::with_capacity(self.height as usize);
+
+        for y in 0..self.height {
+            row_runs.push(encode_row(self, y));
+        }
+
+        output.push_str(&row_runs.join("$"));
+        output.push_str("!\n");
+
+        output
+    }
+}
+
+fn encode_row(grid: &Grid, y: i32) -> String {
+    let mut runs = String::new();
+    let mut run_tag = None;
+    let mut run_len = 0u32;
+
+    for x in 0..grid.width {
+        let alive = grid.cell(x, y).map(|cell| cell.is_alive()).unwrap_or(false);
+        let tag = if alive { 'o' } else { 'b' };
+
+        match run_tag {
+            Some(t) if t == tag => run_len += 1,
+            _ => {
+                if let Some(t) = run_tag {
+                    push_run(&mut runs, run_len, t);
+                }
+                run_tag = Some(tag);
+                run_len = 1;
+            }
+        }
+    }
+
+    // a trailing run of dead cells carries no information in RLE
+    if let Some(t) = run_tag {
+        if t != 'b' {
+            push_run(&mut runs, run_len, t);
+        }
+    }
+
+    runs
+}
+
+fn push_run(runs: &mut String, len: u32, tag: char) {
+    if len > 1 {
+        runs.push_str(&len.to_string());
+    }
+    runs.push(tag);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_a_plaintext_glider() {
+        let grid = Grid::from_plaintext(".*.\n..*\n***\n");
+
+        assert!(grid.cell(1, 0).unwrap().is_alive());
+        assert!(!grid.cell(0, 0).unwrap().is_alive());
+        assert!(grid.cell(0, 2).unwrap().is_alive());
+    }
+
+    #[test]
+    fn it_should_parse_an_rle_glider() {
+        let rle = "#C Glider\nx = 3, y = 3, rule = B3/S23\nbob$2bo$3o!\n";
+        let grid = Grid::from_rle(rle);
+
+        assert!(grid.cell(1, 0).unwrap().is_alive());
+        assert!(grid.cell(2, 1).unwrap().is_alive());
+        assert!(grid.cell(0, 2).unwrap().is_alive());
+        assert!(grid.cell(1, 2).unwrap().is_alive());
+        assert!(grid.cell(2, 2).unwrap().is_alive());
+    }
+
+    #[test]
+    fn it_should_round_trip_through_rle() {
+        let rle = "x = 3, y = 3, rule = B3/S23\nbob$2bo$3o!\n";
+        let grid = Grid::from_rle(rle);
+        let round_tripped = Grid::from_rle(&grid.to_rle());
+
+        for y in 0..3 {
+            for x in 0..3 {
+                assert_eq!(
+                    grid.cell(x, y).unwrap().is_alive(),
+                    round_tripped.cell(x, y).unwrap().is_alive()
+                );
+            }
+        }
+    }
+}
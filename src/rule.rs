@@ -0,0 +1,117 @@
+/// A cellular automaton rule in B/S (birth/survival) notation, e.g. `"B3/S23"`
+/// for Conway's Game of Life or `"B36/S23"` for HighLife.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    /// `birth[n]` is true when a dead cell with `n` live neighbours is born.
+    pub birth: [bool; 9],
+    /// `survival[n]` is true when a live cell with `n` live neighbours survives.
+    pub survival: [bool; 9],
+}
+
+impl Rule {
+    /// The standard Conway's Game of Life rule.
+    pub const CONWAY: &'static str = "B3/S23";
+
+    /// Parses a rule from B/S notation, e.g. `"B3/S23"`. Either side may be
+    /// empty (e.g. `"B2/S"` for Seeds). Duplicate digits are ignored and only
+    /// digits 0-8 are meaningful.
+    pub fn parse(notation: &str) -> Rule {
+        let mut parts = notation.split('/');
+        let birth_part = parts.next().unwrap_or("");
+        let survival_part = parts.next().unwrap_or("");
+
+        Rule {
+            birth: Rule::parse_counts(birth_part, 'B'),
+            survival: Rule::parse_counts(survival_part, 'S'),
+        }
+    }
+
+    fn parse_counts(part: &str, tag: char) -> [bool; 9] {
+        let mut counts = [false; 9];
+        let digits = part.strip_prefix(tag).unwrap_or(part);
+
+        for digit in digits.chars() {
+            if let Some(n) = digit.to_digit(10) {
+                if (n as usize) < counts.len() {
+                    counts[n as usize] = true;
+                }
+            }
+        }
+
+        counts
+    }
+}
+
+impl Default for Rule {
+    fn default() -> Rule {
+        Rule::parse(Rule::CONWAY)
+    }
+}
+
+impl std::fmt::Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "B")?;
+        for (n, born) in self.birth.iter().enumerate() {
+            if *born {
+                write!(f, "{}", n)?;
+            }
+        }
+
+        write!(f, "/S")?;
+        for (n, survives) in self.survival.iter().enumerate() {
+            if *survives {
+                write!(f, "{}", n)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_conway_rules() {
+        let rule = Rule::parse("B3/S23");
+
+        assert_eq!(rule.birth, [false, false, false, true, false, false, false, false, false]);
+        assert_eq!(
+            rule.survival,
+            [false, false, true, true, false, false, false, false, false]
+        );
+    }
+
+    #[test]
+    fn it_should_parse_highlife_rules() {
+        let rule = Rule::parse("B36/S23");
+
+        assert!(rule.birth[3]);
+        assert!(rule.birth[6]);
+        assert!(!rule.birth[2]);
+    }
+
+    #[test]
+    fn it_should_parse_an_empty_survival_set() {
+        let rule = Rule::parse("B2/S");
+
+        assert!(rule.birth[2]);
+        assert_eq!(rule.survival, [false; 9]);
+    }
+
+    #[test]
+    fn it_should_ignore_duplicate_digits() {
+        let rule = Rule::parse("B33/S22");
+
+        assert_eq!(rule.birth.iter().filter(|alive| **alive).count(), 1);
+        assert_eq!(rule.survival.iter().filter(|alive| **alive).count(), 1);
+    }
+
+    #[test]
+    fn it_should_round_trip_through_display_and_parse() {
+        let rule = Rule::parse("B36/S23");
+
+        assert_eq!(Rule::parse(&rule.to_string()), rule);
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::Rule;
+
+const NEIGHBOUR_OFFSETS: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// An unbounded Game of Life universe that only stores live cells, so empty
+/// space costs nothing and patterns are free to travel past where a fixed
+/// `Grid` would have clipped them.
+#[derive(Debug, Clone)]
+pub struct SparseGrid {
+    live_cells: HashSet<(i64, i64)>,
+    rule: Rule,
+}
+
+impl SparseGrid {
+    pub fn new(rule: Rule) -> SparseGrid {
+        SparseGrid {
+            live_cells: HashSet::new(),
+            rule,
+        }
+    }
+
+    pub fn from_seed(live_cells: &[(i64, i64)], rule: Rule) -> SparseGrid {
+        SparseGrid {
+            live_cells: live_cells.iter().copied().collect(),
+            rule,
+        }
+    }
+
+    /// Number of currently live cells.
+    pub fn live_count(&self) -> usize {
+        self.live_cells.len()
+    }
+
+    /// Iterates over the coordinates of live cells, e.g. to render a
+    /// viewport window over the infinite universe.
+    pub fn live_cells(&self) -> impl Iterator<Item = &(i64, i64)> {
+        self.live_cells.iter()
+    }
+
+    pub fn is_alive(&self, x: i64, y: i64) -> bool {
+        self.live_cells.contains(&(x, y))
+    }
+
+    pub fn toggle_cell(&mut self, x: i64, y: i64) -> &SparseGrid {
+        if !self.live_cells.remove(&(x, y)) {
+            self.live_cells.insert((x, y));
+        }
+        self
+    }
+
+    pub fn next_state(&mut self) -> &SparseGrid {
+        let mut neighbour_tally: HashMap<(i64, i64), u8> = HashMap::new();
+
+        for &(x, y) in &self.live_cells {
+            for (dx, dy) in NEIGHBOUR_OFFSETS {
+                let neighbour = (x + dx, y + dy);
+                *neighbour_tally.entry(neighbour).or_insert(0) += 1;
+            }
+        }
+
+        let rule = self.rule;
+        let live_cells = &self.live_cells;
+
+        let next_live_cells = neighbour_tally
+            .into_iter()
+            .filter_map(|(coordinate, count)| {
+                let count = count.min(8) as usize;
+                let is_alive = live_cells.contains(&coordinate);
+                let survives = is_alive && rule.survival[count];
+                let born = !is_alive && rule.birth[count];
+
+                (survives || born).then_some(coordinate)
+            })
+            .collect();
+
+        self.live_cells = next_live_cells;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_count_live_cells_from_seed() {
+        let grid = SparseGrid::from_seed(&[(0, 0), (1, 0), (2, 0)], Rule::default());
+
+        assert_eq!(grid.live_count(), 3);
+    }
+
+    #[test]
+    fn it_should_oscillate_a_blinker_without_a_bounding_box() {
+        // horizontal blinker centred far from the origin
+        let mut grid = SparseGrid::from_seed(&[(100, 100), (101, 100), (102, 100)], Rule::default());
+
+        grid.next_state();
+
+        assert_eq!(grid.live_count(), 3);
+        assert!(grid.is_alive(101, 99));
+        assert!(grid.is_alive(101, 100));
+        assert!(grid.is_alive(101, 101));
+    }
+
+    #[test]
+    fn it_should_let_a_glider_travel_unbounded() {
+        let mut grid = SparseGrid::from_seed(
+            &[(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)],
+            Rule::default(),
+        );
+
+        for _ in 0..4 {
+            grid.next_state();
+        }
+
+        assert_eq!(grid.live_count(), 5);
+        // after one full period the glider has moved one cell down and right
+        for (x, y) in [(2, 1), (3, 2), (1, 3), (2, 3), (3, 3)] {
+            assert!(grid.is_alive(x, y));
+        }
+    }
+}